@@ -0,0 +1,154 @@
+use core::{
+    cell::Cell,
+    fmt,
+    future::Future,
+    task::{Context, Poll, Waker},
+};
+
+use futures_util::Stream;
+
+use crate::NbResultExt;
+
+/// Error returned by a future created by [`abortable_nb_future`] when it was aborted via its
+/// [`AbortHandle`] before completing. A stream created by [`abortable_nb_stream`] does not
+/// yield this error on abort; it ends with `None` instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Aborted;
+
+/// Shared state backing an [`AbortHandle`]/[`AbortRegistration`] pair.
+///
+/// The state is owned by the caller (on the stack or in a `static`) and only ever *borrowed* by
+/// the handle and the registration, so no heap allocation is required. This keeps abortable
+/// futures usable on `no_std` targets without a global allocator, at the cost of requiring the
+/// state to outlive both the handle and the future/stream built from it.
+///
+/// Note that, unlike an `Arc`-backed design, this state is not `Sync`: `AbortHandle::abort` must
+/// be called from the same execution context that polls the future/stream (the common case on a
+/// single-core `no_std` target). Aborting from another thread isn't supported.
+#[derive(Default)]
+pub struct AbortState {
+    aborted: Cell<bool>,
+    waker: Cell<Option<Waker>>,
+}
+
+impl fmt::Debug for AbortState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbortState")
+            .field("aborted", &self.aborted.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AbortState {
+    /// Creates a fresh, not-yet-aborted state.
+    pub const fn new() -> Self {
+        Self {
+            aborted: Cell::new(false),
+            waker: Cell::new(None),
+        }
+    }
+
+    /// Splits the state into a handle used to abort, and a registration consumed when building
+    /// an abortable future or stream.
+    pub fn split(&self) -> (AbortHandle<'_>, AbortRegistration<'_>) {
+        (AbortHandle { inner: self }, AbortRegistration { inner: self })
+    }
+}
+
+/// A registration token produced by [`AbortState::split`], consumed when building an abortable
+/// future or stream.
+#[derive(Debug, Clone, Copy)]
+pub struct AbortRegistration<'a> {
+    inner: &'a AbortState,
+}
+
+/// A handle that can abort the future or stream built from its paired [`AbortRegistration`].
+#[derive(Debug, Clone, Copy)]
+pub struct AbortHandle<'a> {
+    inner: &'a AbortState,
+}
+
+impl AbortHandle<'_> {
+    /// Aborts the associated future or stream, waking it so it can observe the cancellation.
+    ///
+    /// Because [`AbortState`] is `Cell`-based rather than atomic, `AbortHandle` is `!Sync`: this
+    /// must be called from the same execution context that polls the future/stream it aborts,
+    /// not concurrently from another thread.
+    pub fn abort(&self) {
+        self.inner.aborted.set(true);
+        if let Some(waker) = self.inner.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` if [`AbortHandle::abort`] has already been called.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.get()
+    }
+}
+
+/// Wraps a `nb`-style polling function into an abortable future.
+///
+/// The returned future resolves to `Err(Aborted)` as soon as the [`AbortHandle`] paired with
+/// `registration` is used, otherwise it behaves like [`crate::poll_nb_future`] and resolves to
+/// `Ok(Ok(value))` or `Ok(Err(error))` once `poll_fn` returns `Ok` or `Err(nb::Error::Other)`.
+///
+/// This deliberately takes an [`AbortRegistration`] rather than returning `(future, AbortHandle)`
+/// directly: without an allocator there is nowhere for this function to put the shared abort
+/// state, so the caller must own an [`AbortState`] (on the stack or in a `static`) and pass in the
+/// registration obtained from [`AbortState::split`]. Call `split` first to get the paired handle:
+///
+/// ```ignore
+/// let state = AbortState::new();
+/// let (handle, registration) = state.split();
+/// let future = abortable_nb_future(poll_fn, registration);
+/// ```
+pub fn abortable_nb_future<'a, T, E, F>(
+    mut poll_fn: F,
+    registration: AbortRegistration<'a>,
+) -> impl Future<Output = Result<Result<T, E>, Aborted>> + 'a
+where
+    F: FnMut() -> nb::Result<T, E> + 'a,
+{
+    let inner = registration.inner;
+
+    futures_util::future::poll_fn(move |ctx: &mut Context<'_>| {
+        if inner.aborted.get() {
+            return Poll::Ready(Err(Aborted));
+        }
+        inner.waker.set(Some(ctx.waker().clone()));
+        if inner.aborted.get() {
+            return Poll::Ready(Err(Aborted));
+        }
+        poll_fn().into_poll(ctx).map(Ok)
+    })
+}
+
+/// Wraps a `nb`-style polling function into an abortable stream.
+///
+/// The returned stream behaves like [`crate::poll_nb_stream`], except that it ends (yields
+/// `None`) as soon as the [`AbortHandle`] paired with `registration` is used.
+///
+/// Like [`abortable_nb_future`], this takes an [`AbortRegistration`] built from
+/// [`AbortState::split`] instead of returning `(stream, AbortHandle)` directly, since the
+/// no-alloc `AbortState` must be owned by the caller rather than by this function.
+pub fn abortable_nb_stream<'a, T, E, F>(
+    mut poll_fn: F,
+    registration: AbortRegistration<'a>,
+) -> impl Stream<Item = Result<T, E>> + 'a
+where
+    F: FnMut() -> nb::Result<T, E> + Unpin + 'a,
+{
+    let inner = registration.inner;
+
+    futures_util::stream::poll_fn(move |ctx: &mut Context<'_>| {
+        if inner.aborted.get() {
+            return Poll::Ready(None);
+        }
+        inner.waker.set(Some(ctx.waker().clone()));
+        if inner.aborted.get() {
+            return Poll::Ready(None);
+        }
+        poll_fn().into_poll(ctx).map(Some)
+    })
+}
@@ -0,0 +1,201 @@
+#[cfg(not(feature = "std"))]
+compile_error!("the `io` feature bridges to `std::io::Error`/`std::io::Result` and therefore requires the `std` feature to also be enabled");
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::io::{AsyncRead, AsyncWrite};
+
+use crate::{ImmediateWake, NbResultExt, RePollScheduler};
+
+/// Non-blocking byte read, mirrored after [`std::io::Read`] but returning `nb::Result`.
+pub trait NbRead {
+    /// The error type returned on a non-`WouldBlock` failure.
+    type Error;
+    /// Attempts to read bytes from the source into `buf`, returning the number of bytes read.
+    fn read(&mut self, buf: &mut [u8]) -> nb::Result<usize, Self::Error>;
+}
+
+/// Non-blocking byte write, mirrored after [`std::io::Write`] but returning `nb::Result`.
+pub trait NbWrite {
+    /// The error type returned on a non-`WouldBlock` failure.
+    type Error;
+    /// Attempts to write `buf` to the sink, returning the number of bytes written.
+    fn write(&mut self, buf: &[u8]) -> nb::Result<usize, Self::Error>;
+    /// Attempts to flush any buffered data to the sink.
+    fn flush(&mut self) -> nb::Result<(), Self::Error>;
+}
+
+/// Adapts a non-blocking byte-oriented peripheral or socket into the `futures_util::io`
+/// ecosystem.
+///
+/// `T` only needs to implement [`NbRead`]/[`NbWrite`]; [`NbIo`] translates
+/// [`nb::Error::WouldBlock`] into [`Poll::Pending`] (via [`crate::NbResultExt::into_poll_with`])
+/// so the wrapped value can be used with `futures_util::io::copy`, `BufReader`, codecs, and the
+/// rest of the `futures-io` ecosystem without hand-writing poll glue.
+///
+/// By default (via [`NbIo::new`]) re-polling on `WouldBlock` wakes the task immediately, just
+/// like [`crate::NbResultExt::into_poll`]. Use [`NbIo::with_scheduler`] to plug in a
+/// [`RePollScheduler`] (e.g. [`crate::BackoffScheduler`]) instead, so the adaptor doesn't busy-spin
+/// a core while waiting on the peripheral.
+#[derive(Debug)]
+pub struct NbIo<T, S = ImmediateWake> {
+    inner: T,
+    scheduler: S,
+}
+
+impl<T> NbIo<T, ImmediateWake> {
+    /// Wraps `inner` so it can be driven through the `futures_util::io` traits, waking the task
+    /// immediately on every `WouldBlock`.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            scheduler: ImmediateWake,
+        }
+    }
+}
+
+impl<T, S> NbIo<T, S> {
+    /// Wraps `inner`, delegating the re-poll decision on `WouldBlock` to `scheduler` instead of
+    /// waking the task immediately.
+    pub fn with_scheduler(inner: T, scheduler: S) -> Self {
+        Self { inner, scheduler }
+    }
+
+    /// Returns the wrapped value, discarding the adaptor.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T, S> AsyncRead for NbIo<T, S>
+where
+    T: NbRead + Unpin,
+    T::Error: Into<std::io::Error>,
+    S: RePollScheduler + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.inner
+            .read(buf)
+            .into_poll_with(ctx, &mut self.scheduler)
+            .map_err(Into::into)
+    }
+}
+
+impl<T, S> AsyncWrite for NbIo<T, S>
+where
+    T: NbWrite + Unpin,
+    T::Error: Into<std::io::Error>,
+    S: RePollScheduler + Unpin,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.inner
+            .write(buf)
+            .into_poll_with(ctx, &mut self.scheduler)
+            .map_err(Into::into)
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        self.inner
+            .flush()
+            .into_poll_with(ctx, &mut self.scheduler)
+            .map_err(Into::into)
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        self.poll_flush(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use futures_util::{
+        io::{AsyncRead, AsyncWrite},
+        task::noop_waker,
+    };
+
+    use super::{Context, NbIo, NbRead, NbWrite, Pin, Poll};
+
+    struct FakeIo {
+        read_would_block: bool,
+        write_would_block: bool,
+    }
+
+    impl NbRead for FakeIo {
+        type Error = io::Error;
+
+        fn read(&mut self, _buf: &mut [u8]) -> nb::Result<usize, Self::Error> {
+            if self.read_would_block {
+                Err(nb::Error::WouldBlock)
+            } else {
+                Ok(0)
+            }
+        }
+    }
+
+    impl NbWrite for FakeIo {
+        type Error = io::Error;
+
+        fn write(&mut self, _buf: &[u8]) -> nb::Result<usize, Self::Error> {
+            if self.write_would_block {
+                Err(nb::Error::WouldBlock)
+            } else {
+                Err(nb::Error::Other(io::Error::new(io::ErrorKind::Other, "boom")))
+            }
+        }
+
+        fn flush(&mut self) -> nb::Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_poll_read_would_block_is_pending() {
+        let mut io = NbIo::new(FakeIo {
+            read_would_block: true,
+            write_would_block: true,
+        });
+        let waker = noop_waker();
+        let mut ctx = Context::from_waker(&waker);
+        let mut buf = [0u8; 4];
+
+        let poll = Pin::new(&mut io).poll_read(&mut ctx, &mut buf);
+
+        assert!(poll.is_pending());
+    }
+
+    #[test]
+    fn test_poll_write_other_is_ready_err() {
+        let mut io = NbIo::new(FakeIo {
+            read_would_block: true,
+            write_would_block: false,
+        });
+        let waker = noop_waker();
+        let mut ctx = Context::from_waker(&waker);
+
+        let poll = Pin::new(&mut io).poll_write(&mut ctx, b"hi");
+
+        match poll {
+            Poll::Ready(Err(err)) => assert_eq!(err.kind(), io::ErrorKind::Other),
+            other => panic!("expected Poll::Ready(Err(_)), got {other:?}"),
+        }
+    }
+}
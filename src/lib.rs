@@ -2,8 +2,14 @@
 
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 
+pub use crate::abortable::{
+    abortable_nb_future, abortable_nb_stream, AbortHandle, AbortRegistration, AbortState, Aborted,
+};
+#[cfg(feature = "io")]
+pub use crate::io::{NbIo, NbRead, NbWrite};
+pub use crate::scheduler::{BackoffScheduler, ImmediateWake, RePollScheduler};
 #[cfg(feature = "std")]
-pub use crate::std::IntoNbResult;
+pub use crate::std::{IntoNbResult, ThreadBackoffScheduler};
 use core::{
     fmt::Debug,
     future::Future,
@@ -11,6 +17,10 @@ use core::{
 };
 use futures_util::Stream;
 
+mod abortable;
+#[cfg(feature = "io")]
+mod io;
+mod scheduler;
 #[cfg(feature = "std")]
 mod std;
 #[cfg(test)]
@@ -38,6 +48,13 @@ pub trait NbResultExt<T, E> {
     /// Converts the `nb::Result` value into the corresponding `Poll` one.
     /// For the [`nb::Error::WouldBlock`] value it calls a waker.
     fn into_poll(self, ctx: &mut Context<'_>) -> Poll<Result<T, E>>;
+    /// Like [`NbResultExt::into_poll`], but delegates the re-poll decision on `WouldBlock` to
+    /// `scheduler` instead of always waking the task immediately.
+    fn into_poll_with<S: RePollScheduler>(
+        self,
+        ctx: &mut Context<'_>,
+        scheduler: &mut S,
+    ) -> Poll<Result<T, E>>;
     /// Returns true if the result is [`nb::Error::WouldBlock`].
     fn is_would_block(&self) -> bool;
 }
@@ -95,11 +112,25 @@ impl<T, E> NbResultExt<T, E> for nb::Result<T, E> {
     }
 
     fn into_poll(self, ctx: &mut Context<'_>) -> Poll<Result<T, E>> {
+        self.into_poll_with(ctx, &mut ImmediateWake)
+    }
+
+    fn into_poll_with<S: RePollScheduler>(
+        self,
+        ctx: &mut Context<'_>,
+        scheduler: &mut S,
+    ) -> Poll<Result<T, E>> {
         match self {
-            Ok(output) => Poll::Ready(Ok(output)),
-            Err(nb::Error::Other(err)) => Poll::Ready(Err(err)),
+            Ok(output) => {
+                scheduler.reset();
+                Poll::Ready(Ok(output))
+            }
+            Err(nb::Error::Other(err)) => {
+                scheduler.reset();
+                Poll::Ready(Err(err))
+            }
             Err(nb::Error::WouldBlock) => {
-                ctx.waker().wake_by_ref();
+                scheduler.schedule(ctx.waker());
                 Poll::Pending
             }
         }
@@ -128,6 +159,182 @@ where
     futures_util::stream::poll_fn(move |ctx| poll_fn().into_poll(ctx).map(Some))
 }
 
+/// Like [`poll_nb_stream`], but ends the stream after the first `Err(nb::Error::Other)` instead
+/// of polling `poll_fn` again.
+///
+/// `poll_nb_stream` is documented as infinite, but for many drivers an `Err(Other)` is a
+/// permanent failure, so re-invoking `poll_fn` on the next poll just re-runs an operation that
+/// has already failed. This yields items until the first error (emitted once) and thereafter
+/// returns `Poll::Ready(None)` without ever polling `poll_fn` again, matching the contract of a
+/// fused stream.
+pub fn poll_nb_stream_fused<T, E, F>(mut poll_fn: F) -> impl Stream<Item = Result<T, E>>
+where
+    F: FnMut() -> nb::Result<T, E> + Unpin,
+{
+    let mut terminated = false;
+    futures_util::stream::poll_fn(move |ctx| {
+        if terminated {
+            return Poll::Ready(None);
+        }
+        match poll_fn().into_poll(ctx) {
+            Poll::Ready(Ok(value)) => Poll::Ready(Some(Ok(value))),
+            Poll::Ready(Err(err)) => {
+                terminated = true;
+                Poll::Ready(Some(Err(err)))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    })
+}
+
+/// Convert a function that returns `nb::Result<T, E>` into an infinite stream that surfaces
+/// every poll outcome, including [`nb::Error::WouldBlock`], as a stream item.
+///
+/// Unlike [`poll_nb_stream`], this never parks the task: `poll_fn` is called exactly once per
+/// poll and its raw result (`Ok`, `Err(Other)` or `Err(WouldBlock)`) is yielded directly. This is
+/// useful for driving state machines or integrating with an external event loop that decides
+/// when to re-poll, rather than forcing the all-or-nothing resolution of `poll_nb_stream`.
+pub fn poll_nb_immediate_stream<T, E, F>(mut poll_fn: F) -> impl Stream<Item = nb::Result<T, E>>
+where
+    F: FnMut() -> nb::Result<T, E> + Unpin,
+{
+    futures_util::stream::poll_fn(move |_ctx| Poll::Ready(Some(poll_fn())))
+}
+
+/// Like [`poll_nb_future`], but re-polling on `WouldBlock` is delegated to `scheduler` instead
+/// of always waking the task immediately, which otherwise spins the executor at 100% CPU.
+pub fn poll_nb_future_with<T, E, F, S>(
+    mut poll_fn: F,
+    mut scheduler: S,
+) -> impl Future<Output = Result<T, E>>
+where
+    F: FnMut() -> nb::Result<T, E>,
+    S: RePollScheduler,
+{
+    futures_util::future::poll_fn(move |ctx| poll_fn().into_poll_with(ctx, &mut scheduler))
+}
+
+/// Like [`poll_nb_stream`], but re-polling on `WouldBlock` is delegated to `scheduler` instead
+/// of always waking the task immediately, which otherwise spins the executor at 100% CPU.
+pub fn poll_nb_stream_with<T, E, F, S>(
+    mut poll_fn: F,
+    mut scheduler: S,
+) -> impl Stream<Item = Result<T, E>>
+where
+    F: FnMut() -> nb::Result<T, E> + Unpin,
+    S: RePollScheduler,
+{
+    futures_util::stream::poll_fn(move |ctx| poll_fn().into_poll_with(ctx, &mut scheduler).map(Some))
+}
+
+/// Races a fixed set of `nb`-style sources and resolves as soon as the first one is ready.
+///
+/// Each source is polled once, in order, on every call. The first one that returns `Ok` or
+/// `Err(nb::Error::Other)` resolves the future with its index within `sources` together with
+/// the corresponding result. If every source returns [`nb::Error::WouldBlock`] the future stays
+/// pending, waking the task so it gets polled again (mirroring [`NbResultExt::into_poll`]).
+///
+/// Sources are taken as `&'a mut dyn FnMut`, one per slot, rather than owned by value like
+/// [`poll_nb_join`]'s tuple. Closures closing over distinct peripheral state have distinct
+/// concrete types, so a homogeneous `[F; N]` can't hold them directly; owning heterogeneous
+/// closures would require boxing them (`Box<dyn FnMut(..)>`), which pulls in an allocator. The
+/// `'a` borrow achieves the same "state persists across polls" requirement without that cost:
+/// the returned future is bounded by `'a`, so the closures (and whatever they capture) must
+/// outlive it, exactly as if the future owned them. This keeps `poll_nb_select` usable on
+/// `no_std` targets without a global allocator, matching [`abortable_nb_future`]'s design.
+pub fn poll_nb_select<'a, T, E, const N: usize>(
+    mut sources: [&'a mut dyn FnMut() -> nb::Result<T, E>; N],
+) -> impl Future<Output = (usize, Result<T, E>)> + 'a {
+    futures_util::future::poll_fn(move |ctx| {
+        for (index, source) in sources.iter_mut().enumerate() {
+            match source() {
+                Ok(value) => return Poll::Ready((index, Ok(value))),
+                Err(nb::Error::Other(err)) => return Poll::Ready((index, Err(err))),
+                Err(nb::Error::WouldBlock) => {}
+            }
+        }
+        ctx.waker().wake_by_ref();
+        Poll::Pending
+    })
+}
+
+/// Trait implemented for tuples of `FnMut() -> nb::Result<T, E>` closures that can be driven to
+/// completion concurrently by [`poll_nb_join`].
+///
+/// There is no need to implement this trait manually; it is implemented for tuples up to arity
+/// four via a macro, mirroring futures-util's `join!`.
+pub trait NbJoinSources<E> {
+    /// The tuple of resolved values produced once every source has completed.
+    type Output;
+    /// The per-source slots accumulated across polls.
+    type State: Default;
+
+    /// Polls every source that has not yet produced a value, storing it in `state`.
+    fn poll_sources(&mut self, state: &mut Self::State) -> Poll<Result<Self::Output, E>>;
+}
+
+macro_rules! impl_nb_join_sources {
+    ($($Fn:ident, $T:ident, $slot:ident);+ $(;)?) => {
+        impl<E, $($T,)+ $($Fn,)+> NbJoinSources<E> for ($($Fn,)+)
+        where
+            $($Fn: FnMut() -> nb::Result<$T, E>,)+
+        {
+            type Output = ($($T,)+);
+            type State = ($(Option<$T>,)+);
+
+            fn poll_sources(&mut self, state: &mut Self::State) -> Poll<Result<Self::Output, E>> {
+                #[allow(non_snake_case)]
+                let ($($Fn,)+) = self;
+                #[allow(non_snake_case)]
+                let ($($slot,)+) = state;
+
+                $(
+                    if $slot.is_none() {
+                        match $Fn() {
+                            Ok(value) => *$slot = Some(value),
+                            Err(nb::Error::Other(err)) => return Poll::Ready(Err(err)),
+                            Err(nb::Error::WouldBlock) => {}
+                        }
+                    }
+                )+
+
+                if $($slot.is_some())&&+ {
+                    Poll::Ready(Ok(($($slot.take().unwrap(),)+)))
+                } else {
+                    Poll::Pending
+                }
+            }
+        }
+    };
+}
+
+impl_nb_join_sources!(F0, T0, s0);
+impl_nb_join_sources!(F0, T0, s0; F1, T1, s1);
+impl_nb_join_sources!(F0, T0, s0; F1, T1, s1; F2, T2, s2);
+impl_nb_join_sources!(F0, T0, s0; F1, T1, s1; F2, T2, s2; F3, T3, s3);
+
+/// Drives a tuple of `nb`-style sources to completion concurrently, resolving once every one of
+/// them has produced a value.
+///
+/// On each poll, every source that has not yet completed is polled once; a source that returns
+/// `Ok` fills its slot, `Err(nb::Error::Other)` short-circuits the whole future, and
+/// `Err(nb::Error::WouldBlock)` leaves its slot empty for the next poll. This lets callers await
+/// a batch of peripheral reads without manually interleaving `nb::block!` loops that would
+/// serialize the waits.
+pub fn poll_nb_join<E, J>(mut sources: J) -> impl Future<Output = Result<J::Output, E>>
+where
+    J: NbJoinSources<E>,
+{
+    let mut state = J::State::default();
+    futures_util::future::poll_fn(move |ctx| match sources.poll_sources(&mut state) {
+        Poll::Ready(result) => Poll::Ready(result),
+        Poll::Pending => {
+            ctx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+}
+
 /// Creates future which always returns `Poll::Pending` at the first `poll` call to transfer the
 /// control flow to the executor.
 pub fn yield_executor() -> impl Future<Output = ()> {
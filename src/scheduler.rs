@@ -0,0 +1,71 @@
+use core::task::Waker;
+
+/// Decides how a pending `nb` poll should be re-scheduled, instead of waking the task
+/// immediately and spinning the executor.
+///
+/// Implementations are passed to [`crate::NbResultExt::into_poll_with`],
+/// [`crate::poll_nb_future_with`] and [`crate::poll_nb_stream_with`].
+pub trait RePollScheduler {
+    /// Schedules a re-poll for the given `waker`, called every time the underlying `nb`
+    /// function returns [`nb::Error::WouldBlock`].
+    fn schedule(&mut self, waker: &Waker);
+
+    /// Called whenever the underlying operation resolves (`Ok` or `Err(Other)`), so stateful
+    /// schedulers can reset themselves before the next round of waiting.
+    fn reset(&mut self) {}
+}
+
+/// Wakes the task immediately on every `WouldBlock`, matching the historical behaviour of
+/// [`crate::NbResultExt::into_poll`]. This busy-spins the executor, but never introduces any
+/// extra latency.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ImmediateWake;
+
+impl RePollScheduler for ImmediateWake {
+    fn schedule(&mut self, waker: &Waker) {
+        waker.wake_by_ref();
+    }
+}
+
+/// Re-schedules a pending poll after an exponentially increasing delay, capped at `max_delay`,
+/// instead of waking the task immediately. This stops `WouldBlock` spins from pinning a core.
+///
+/// The delay unit is caller-defined (milliseconds, timer ticks, ...); `schedule_delayed` is
+/// called with the computed delay and the `Waker` to invoke once it elapses, so callers can
+/// plug in e.g. an `embedded-hal` `DelayNs` or a `std` timer.
+pub struct BackoffScheduler<F> {
+    schedule_delayed: F,
+    initial_delay: u32,
+    max_delay: u32,
+    current_delay: u32,
+}
+
+impl<F> BackoffScheduler<F>
+where
+    F: FnMut(u32, Waker),
+{
+    /// Creates a scheduler that starts at `initial_delay` and doubles on every consecutive
+    /// `WouldBlock`, saturating at `max_delay`.
+    pub fn new(initial_delay: u32, max_delay: u32, schedule_delayed: F) -> Self {
+        Self {
+            schedule_delayed,
+            initial_delay,
+            max_delay,
+            current_delay: initial_delay,
+        }
+    }
+}
+
+impl<F> RePollScheduler for BackoffScheduler<F>
+where
+    F: FnMut(u32, Waker),
+{
+    fn schedule(&mut self, waker: &Waker) {
+        (self.schedule_delayed)(self.current_delay, waker.clone());
+        self.current_delay = self.current_delay.saturating_mul(2).min(self.max_delay);
+    }
+
+    fn reset(&mut self) {
+        self.current_delay = self.initial_delay;
+    }
+}
@@ -1,4 +1,13 @@
-use std::io::ErrorKind;
+use std::{
+    fmt,
+    io::ErrorKind,
+    sync::mpsc::{self, Sender},
+    task::Waker,
+    thread,
+    time::Duration,
+};
+
+use crate::RePollScheduler;
 
 /// Converts [`std::io::Result`] into the [`nb::Result`].
 /// 
@@ -19,3 +28,66 @@ impl<T> IntoNbResult<T, std::io::Error> for std::io::Result<T> {
         }
     }
 }
+
+/// A [`RePollScheduler`] that parks a single reusable background thread for an exponentially
+/// increasing duration (capped at `max_delay`) before waking the task, instead of spinning the
+/// executor.
+///
+/// The timer thread is spawned lazily on the first `WouldBlock` and kept alive for the lifetime
+/// of the scheduler, rather than spawning a new thread on every re-poll.
+pub struct ThreadBackoffScheduler {
+    initial_delay: Duration,
+    max_delay: Duration,
+    current_delay: Duration,
+    timer: Option<Sender<(Duration, Waker)>>,
+}
+
+impl ThreadBackoffScheduler {
+    /// Creates a scheduler that starts at `initial_delay` and doubles on every consecutive
+    /// `WouldBlock`, saturating at `max_delay`.
+    pub fn new(initial_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            max_delay,
+            current_delay: initial_delay,
+            timer: None,
+        }
+    }
+
+    fn timer(&mut self) -> &Sender<(Duration, Waker)> {
+        self.timer.get_or_insert_with(|| {
+            let (sender, receiver) = mpsc::channel::<(Duration, Waker)>();
+            thread::spawn(move || {
+                for (delay, waker) in receiver {
+                    thread::sleep(delay);
+                    waker.wake();
+                }
+            });
+            sender
+        })
+    }
+}
+
+impl fmt::Debug for ThreadBackoffScheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadBackoffScheduler")
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("current_delay", &self.current_delay)
+            .finish()
+    }
+}
+
+impl RePollScheduler for ThreadBackoffScheduler {
+    fn schedule(&mut self, waker: &Waker) {
+        let delay = self.current_delay;
+        let waker = waker.clone();
+        // The channel is unbounded, so the send only fails if the timer thread already exited.
+        let _ = self.timer().send((delay, waker));
+        self.current_delay = self.current_delay.saturating_mul(2).min(self.max_delay);
+    }
+
+    fn reset(&mut self) {
+        self.current_delay = self.initial_delay;
+    }
+}
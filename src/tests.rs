@@ -1,6 +1,10 @@
 use futures_util::StreamExt;
 
-use crate::{poll_nb_future, poll_nb_stream, yield_executor, NbResultExt};
+use crate::{
+    abortable_nb_future, poll_nb_future, poll_nb_future_with, poll_nb_immediate_stream,
+    poll_nb_join, poll_nb_select, poll_nb_stream, poll_nb_stream_fused, yield_executor,
+    AbortState, Aborted, NbResultExt, RePollScheduler,
+};
 
 struct MaybeBlock {
     attempts_per_item: usize,
@@ -93,6 +97,150 @@ fn test_poll_nb_stream() {
     });
 }
 
+#[test]
+fn test_poll_nb_select() {
+    let mut slow = MaybeBlock {
+        value: 1,
+        attempts_per_item: 3,
+        remaining_attemts: 3,
+    };
+    let mut fast = MaybeBlock {
+        value: 100,
+        attempts_per_item: 1,
+        remaining_attemts: 1,
+    };
+
+    let mut slow_source = || slow.poll_me();
+    let mut fast_source = || fast.poll_me();
+
+    let select = poll_nb_select([&mut slow_source, &mut fast_source]);
+    let (index, value) = spin_on::spin_on(select);
+
+    assert_eq!(index, 1);
+    assert_eq!(value, Ok(100));
+}
+
+#[test]
+fn test_poll_nb_join() {
+    let mut first = MaybeBlock {
+        value: 1,
+        attempts_per_item: 1,
+        remaining_attemts: 1,
+    };
+    let mut second = MaybeBlock {
+        value: 100,
+        attempts_per_item: 3,
+        remaining_attemts: 3,
+    };
+
+    let join = poll_nb_join((|| first.poll_me(), || second.poll_me()));
+    let value = spin_on::spin_on(join).unwrap();
+
+    assert_eq!(value, (1, 100));
+}
+
+#[test]
+fn test_abortable_nb_future_completes() {
+    let mut block = MaybeBlock {
+        value: 1,
+        ..MaybeBlock::default()
+    };
+
+    let state = AbortState::new();
+    let (_handle, registration) = state.split();
+    let future = abortable_nb_future(|| block.poll_me(), registration);
+    let value = spin_on::spin_on(future).unwrap().unwrap();
+
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn test_abortable_nb_future_aborted() {
+    let mut block = MaybeBlock::default();
+
+    let state = AbortState::new();
+    let (handle, registration) = state.split();
+    let future = abortable_nb_future(|| block.poll_me(), registration);
+    handle.abort();
+    let result = spin_on::spin_on(future);
+
+    assert_eq!(result, Err(Aborted));
+}
+
+#[test]
+fn test_poll_nb_future_with_custom_scheduler() {
+    use std::{cell::Cell, rc::Rc};
+
+    struct CountingScheduler {
+        scheduled: Rc<Cell<usize>>,
+    }
+
+    impl RePollScheduler for CountingScheduler {
+        fn schedule(&mut self, waker: &std::task::Waker) {
+            self.scheduled.set(self.scheduled.get() + 1);
+            waker.wake_by_ref();
+        }
+    }
+
+    let mut block = MaybeBlock {
+        value: 1,
+        attempts_per_item: 3,
+        remaining_attemts: 3,
+    };
+    let scheduled = Rc::new(Cell::new(0));
+
+    let future = poll_nb_future_with(
+        || block.poll_me(),
+        CountingScheduler {
+            scheduled: scheduled.clone(),
+        },
+    );
+    let value = spin_on::spin_on(future).unwrap();
+
+    assert_eq!(value, 1);
+    assert_eq!(scheduled.get(), 3);
+}
+
+#[test]
+fn test_poll_nb_immediate_stream() {
+    let mut block = MaybeBlock {
+        value: 1,
+        attempts_per_item: 1,
+        remaining_attemts: 1,
+    };
+
+    let mut stream = poll_nb_immediate_stream(move || block.poll_me());
+    spin_on::spin_on(async {
+        assert_eq!(stream.next().await, Some(Err(nb::Error::WouldBlock)));
+        assert_eq!(stream.next().await, Some(Ok(1)));
+        assert_eq!(stream.next().await, Some(Err(nb::Error::WouldBlock)));
+    });
+}
+
+#[test]
+fn test_poll_nb_stream_fused() {
+    use std::{cell::Cell, rc::Rc};
+
+    let calls = Rc::new(Cell::new(0));
+    let poll_calls = calls.clone();
+    let mut stream = poll_nb_stream_fused(move || {
+        poll_calls.set(poll_calls.get() + 1);
+        match poll_calls.get() {
+            1 => Ok(1),
+            2 => Err(nb::Error::Other(())),
+            _ => panic!("poll_fn must not be called again once the stream is terminated"),
+        }
+    });
+
+    spin_on::spin_on(async {
+        assert_eq!(stream.next().await, Some(Ok(1)));
+        assert_eq!(stream.next().await, Some(Err(())));
+        assert_eq!(stream.next().await, None);
+        assert_eq!(stream.next().await, None);
+    });
+    assert_eq!(calls.get(), 2);
+}
+
 #[test]
 fn test_yield() {
     spin_on::spin_on(async {